@@ -0,0 +1,123 @@
+//! Adapter for a [`hyper-util`] legacy client.
+//!
+//! [`hyper-util`]: https://crates.io/crates/hyper-util
+
+use std::{future::Future, pin::Pin, task::Poll};
+
+use hyper::body::{Bytes, Incoming};
+use pin_project::pin_project;
+use tower::Service;
+
+use crate::{HttpClientService, ResponseBody};
+
+/// Wraps a backend `hyper-util` client service so it can be driven through
+/// [`HttpClientService`](crate::HttpClientService).
+///
+/// A blanket `impl Service<_> for HttpClientService<S>` scoped to hyper
+/// responses would conflict with the `reqwest` adapter's blanket impl
+/// (rustc cannot prove a foreign `S` never implements
+/// `Service<reqwest::Request>`), so this crate-local newtype disambiguates
+/// the two backends instead. Typically `S` is a
+/// [`hyper_util::client::legacy::Client`], optionally wrapped in further
+/// `tower` layers (e.g. a [`tower::limit::ConcurrencyLimit`]).
+///
+/// [`hyper_util::client::legacy::Client`]: https://docs.rs/hyper-util/latest/hyper_util/client/legacy/struct.Client.html
+#[derive(Debug, Clone)]
+pub struct HyperClient<S>(S);
+
+impl<S> HyperClient<S> {
+    /// Wraps `inner` for use with [`HttpClientService`](crate::HttpClientService).
+    pub fn new(inner: S) -> Self {
+        Self(inner)
+    }
+}
+
+impl<S, ReqBody> Service<http::Request<ReqBody>> for HttpClientService<HyperClient<S>>
+where
+    S: Service<http::Request<ReqBody>, Response = http::Response<Incoming>>,
+    S::Future: Send + 'static,
+    ReqBody: hyper::body::Body<Data = Bytes> + Send + Unpin + 'static,
+    ReqBody::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+    crate::Error: From<S::Error>,
+{
+    type Response = http::Response<ResponseBody>;
+    type Error = crate::Error;
+    type Future = ExecuteRequestFuture<S::Future>;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        self.0 .0.poll_ready(cx).map_err(crate::Error::from)
+    }
+
+    fn call(&mut self, req: http::Request<ReqBody>) -> Self::Future {
+        ExecuteRequestFuture {
+            future: self.0 .0.call(req),
+        }
+    }
+}
+
+/// Future that resolves to the response or the error the inner service
+/// failed with, converted into [`crate::Error`].
+#[pin_project]
+pub struct ExecuteRequestFuture<F> {
+    #[pin]
+    future: F,
+}
+
+impl<F, Error> Future for ExecuteRequestFuture<F>
+where
+    F: Future<Output = Result<http::Response<Incoming>, Error>>,
+    crate::Error: From<Error>,
+{
+    type Output = crate::Result<http::Response<ResponseBody>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        this.future.poll(cx).map(|result| {
+            result
+                .map(|response| response.map(ResponseBody::new))
+                .map_err(crate::Error::from)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hyper_util::{client::legacy::Client, rt::TokioExecutor};
+    use tokio_test::{assert_pending, assert_ready_ok};
+    use tower::{limit::ConcurrencyLimit, Service};
+
+    use crate::{HttpClientService, RequestBuilderExt};
+
+    use super::HyperClient;
+
+    #[test]
+    fn test_poll_ready_propagates_inner_backpressure() {
+        // `HttpClientService::poll_ready` must delegate to the wrapped
+        // hyper service, so a `ConcurrencyLimit` placed underneath it can
+        // still apply backpressure to callers.
+        let client = Client::builder(TokioExecutor::new()).build_http();
+        let mut service =
+            HttpClientService::new(HyperClient::new(ConcurrencyLimit::new(client, 1)));
+
+        let mut task = tokio_test::task::spawn(());
+        task.enter(|cx, _| {
+            assert_ready_ok!(service.poll_ready(cx));
+        });
+
+        let request = http::request::Builder::new()
+            .method(http::Method::GET)
+            .uri("http://localhost/hello")
+            .empty_body()
+            .unwrap();
+        // Acquire the single permit without driving the resulting future,
+        // saturating the concurrency limit.
+        let _future = service.call(request);
+
+        task.enter(|cx, _| {
+            assert_pending!(service.poll_ready(cx));
+        });
+    }
+}
@@ -0,0 +1,6 @@
+//! Adapters bridging concrete HTTP client backends to
+//! [`HttpClientService`](crate::HttpClientService).
+
+#[cfg(feature = "hyper-util")]
+pub mod hyper;
+pub mod reqwest;
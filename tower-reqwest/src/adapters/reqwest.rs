@@ -9,7 +9,7 @@ use hyper::body::Bytes;
 use pin_project::pin_project;
 use tower::Service;
 
-use crate::HttpClientService;
+use crate::{HttpClientService, ResponseBody};
 
 type BoxError = Box<dyn std::error::Error + Send + Sync>;
 
@@ -26,19 +26,23 @@ where
     http::Response<reqwest::Body>: From<S::Response>,
     crate::Error: From<S::Error>,
 {
-    type Response = http::Response<reqwest::Body>;
+    type Response = http::Response<ResponseBody>;
     type Error = crate::Error;
     type Future = ExecuteRequestFuture<S>;
 
     fn poll_ready(
         &mut self,
-        _cx: &mut std::task::Context<'_>,
+        cx: &mut std::task::Context<'_>,
     ) -> std::task::Poll<Result<(), Self::Error>> {
-        Poll::Ready(Ok(()))
+        self.0.poll_ready(cx).map_err(crate::Error::from)
     }
 
     fn call(&mut self, req: http::Request<ReqBody>) -> Self::Future {
-        let req = req.map(|body| body.map_err(BoxError::from).boxed());
+        // `reqwest::Body` has no `From`/`Into` impl for an arbitrary
+        // `http_body::Body`, only for concrete owned buffers, so
+        // `reqwest::Request::try_from` can't take the body as-is; wrap it
+        // explicitly instead.
+        let req = req.map(|body| reqwest::Body::wrap(body.map_err(BoxError::from).boxed()));
 
         let future = reqwest::Request::try_from(req).map(|reqw| self.0.call(reqw));
         ExecuteRequestFuture::new(future)
@@ -89,7 +93,7 @@ where
     crate::Error: From<S::Error>,
     http::Response<reqwest::Body>: From<S::Response>,
 {
-    type Output = crate::Result<http::Response<reqwest::Body>>;
+    type Output = crate::Result<http::Response<ResponseBody>>;
 
     fn poll(
         self: std::pin::Pin<&mut Self>,
@@ -97,9 +101,14 @@ where
     ) -> std::task::Poll<Self::Output> {
         let this = self.project();
         match this.inner.project() {
-            InnerProj::Future { fut } => {
-                fut.poll(cx).map_ok(From::from).map_err(crate::Error::from)
-            }
+            InnerProj::Future { fut } => fut.poll(cx).map(|result| {
+                result
+                    .map(|response| {
+                        http::Response::<reqwest::Body>::from(response)
+                            .map(ResponseBody::new)
+                    })
+                    .map_err(crate::Error::from)
+            }),
             InnerProj::Error { error } => {
                 let error = error.take().expect("Polled after ready");
                 Poll::Ready(Err(error))
@@ -111,18 +120,18 @@ where
 #[cfg(test)]
 mod tests {
     use http::{header::USER_AGENT, HeaderName, HeaderValue};
-    use http_body_util::BodyExt;
     use pretty_assertions::assert_eq;
     use reqwest::Client;
     use serde::{Deserialize, Serialize};
-    use tower::{Service, ServiceBuilder, ServiceExt};
+    use tokio_test::{assert_pending, assert_ready_ok};
+    use tower::{limit::ConcurrencyLimit, Service, ServiceBuilder, ServiceExt};
     use tower_http::{request_id::MakeRequestUuid, ServiceBuilderExt};
     use wiremock::{
         matchers::{method, path},
         Mock, MockServer, ResponseTemplate,
     };
 
-    use crate::HttpClientLayer;
+    use crate::{HttpClientLayer, HttpClientService, RequestBuilderExt, ResponseExt};
 
     #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
     struct Info {
@@ -131,14 +140,6 @@ mod tests {
         request_id: Option<String>,
     }
 
-    impl Info {
-        async fn from_body(body: reqwest::Body) -> anyhow::Result<Self> {
-            let body_bytes = body.collect().await?.to_bytes();
-            let info: Info = serde_json::from_slice(&body_bytes)?;
-            Ok(info)
-        }
-    }
-
     #[tokio::test]
     async fn test_http_client_layer() -> anyhow::Result<()> {
         // Start a background HTTP server on a random local port
@@ -172,8 +173,7 @@ mod tests {
         let request = http::request::Builder::new()
             .method(http::Method::GET)
             .uri(format!("{mock_uri}/hello"))
-            // TODO Make in easy to create requests without body.
-            .body(http_body_util::Empty::new())?;
+            .empty_body()?;
 
         let response = ServiceBuilder::new()
             .layer(HttpClientLayer)
@@ -182,7 +182,7 @@ mod tests {
             .await?;
         assert!(response.status().is_success());
         // Try to read body
-        let info = Info::from_body(response.into_body()).await?;
+        let info: Info = response.json().await?;
         assert!(info.request_id.is_none());
 
         // TODO Find the way to avoid cloning the service.
@@ -202,11 +202,47 @@ mod tests {
         );
 
         // Try to read body again.
-        let info = Info::from_body(response.into_body()).await?;
+        let info: Info = response.json().await?;
         assert_eq!(info.student, "Vasya Pupkin");
         assert_eq!(info.answer, 42);
         assert!(info.request_id.is_some());
 
         Ok(())
     }
+
+    #[test]
+    fn test_poll_ready_propagates_inner_backpressure() {
+        // `HttpClientService::poll_ready` must delegate to the inner
+        // service, so a `ConcurrencyLimit` placed underneath it can still
+        // apply backpressure to callers.
+        //
+        // `poll_ready` takes no request, so nothing pins which `ReqBody`
+        // instantiation of the blanket `Service` impl to use; name it
+        // explicitly (the same `Empty<Bytes>` the request below uses).
+        type Req = http::Request<http_body_util::Empty<hyper::body::Bytes>>;
+
+        let mut service = HttpClientService::new(ConcurrencyLimit::new(Client::new(), 1));
+        let poll_ready = |service: &mut HttpClientService<ConcurrencyLimit<Client>>,
+                          cx: &mut std::task::Context<'_>| {
+            Service::<Req>::poll_ready(service, cx)
+        };
+
+        let mut task = tokio_test::task::spawn(());
+        task.enter(|cx, _| {
+            assert_ready_ok!(poll_ready(&mut service, cx));
+        });
+
+        let request = http::request::Builder::new()
+            .method(http::Method::GET)
+            .uri("http://localhost/hello")
+            .empty_body()
+            .unwrap();
+        // Acquire the single permit without driving the resulting future,
+        // saturating the concurrency limit.
+        let _future = service.call(request);
+
+        task.enter(|cx, _| {
+            assert_pending!(poll_ready(&mut service, cx));
+        });
+    }
 }
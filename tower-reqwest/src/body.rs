@@ -0,0 +1,37 @@
+//! Backend-agnostic response body.
+
+use hyper::body::Bytes;
+use http_body_util::{combinators::BoxBody, BodyExt};
+
+/// A boxed, backend-agnostic response body.
+///
+/// Each backend adapter (e.g. [`adapters::reqwest`](crate::adapters::reqwest))
+/// wraps whatever concrete body type its client returns in a
+/// [`ResponseBody`], so [`HttpClientService`](crate::HttpClientService)
+/// always resolves to `http::Response<ResponseBody>` regardless of the
+/// backend behind it. That lets `tower-http` layers generic over
+/// [`http_body::Body`] sit on top of any backend without naming it.
+pub struct ResponseBody(BoxBody<Bytes, crate::Error>);
+
+impl ResponseBody {
+    /// Boxes `body`, converting its error type into [`crate::Error`].
+    pub(crate) fn new<B>(body: B) -> Self
+    where
+        B: http_body::Body<Data = Bytes> + Send + Sync + 'static,
+        B::Error: Into<crate::Error>,
+    {
+        Self(body.map_err(Into::into).boxed())
+    }
+}
+
+impl http_body::Body for ResponseBody {
+    type Data = Bytes;
+    type Error = crate::Error;
+
+    fn poll_frame(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Result<http_body::Frame<Self::Data>, Self::Error>>> {
+        std::pin::Pin::new(&mut self.get_mut().0).poll_frame(cx)
+    }
+}
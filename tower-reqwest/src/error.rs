@@ -0,0 +1,167 @@
+//! Crate error type.
+
+use std::fmt;
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// The error type returned by this crate's [`Service`](tower::Service)
+/// adapters.
+///
+/// Use [`Error::is_builder`], [`Error::is_connect`], [`Error::is_timeout`]
+/// and [`Error::is_response`] to tell transient failures (worth retrying)
+/// apart from ones that are not, e.g. to drive a [`tower::retry::Policy`].
+#[derive(Debug)]
+pub struct Error {
+    kind: Kind,
+    inner: BoxError,
+}
+
+/// Categorizes what stage of a request produced an [`Error`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    /// The request could not be constructed. Never retryable.
+    Builder,
+    /// The backend failed to establish a connection.
+    Connect,
+    /// The request or connection timed out.
+    Timeout,
+    /// Any other failure while sending the request or reading the response.
+    Response,
+}
+
+impl Error {
+    fn new(kind: Kind, inner: impl Into<BoxError>) -> Self {
+        Self {
+            kind,
+            inner: inner.into(),
+        }
+    }
+
+    pub(crate) fn builder(inner: impl Into<BoxError>) -> Self {
+        Self::new(Kind::Builder, inner)
+    }
+
+    pub(crate) fn connect(inner: impl Into<BoxError>) -> Self {
+        Self::new(Kind::Connect, inner)
+    }
+
+    pub(crate) fn timeout(inner: impl Into<BoxError>) -> Self {
+        Self::new(Kind::Timeout, inner)
+    }
+
+    pub(crate) fn response(inner: impl Into<BoxError>) -> Self {
+        Self::new(Kind::Response, inner)
+    }
+
+    /// Returns `true` if the request could not be constructed, e.g. because
+    /// of an invalid URI. Never retryable.
+    pub fn is_builder(&self) -> bool {
+        self.kind == Kind::Builder
+    }
+
+    /// Returns `true` if the backend failed to establish a connection.
+    pub fn is_connect(&self) -> bool {
+        self.kind == Kind::Connect
+    }
+
+    /// Returns `true` if the request or connection timed out.
+    pub fn is_timeout(&self) -> bool {
+        self.kind == Kind::Timeout
+    }
+
+    /// Returns `true` for any other failure while sending the request or
+    /// reading the response.
+    pub fn is_response(&self) -> bool {
+        self.kind == Kind::Response
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.inner.as_ref())
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_builder() {
+            Self::builder(error)
+        } else if error.is_connect() {
+            Self::connect(error)
+        } else if error.is_timeout() {
+            Self::timeout(error)
+        } else {
+            Self::response(error)
+        }
+    }
+}
+
+impl From<hyper::Error> for Error {
+    fn from(error: hyper::Error) -> Self {
+        if error.is_timeout() {
+            Self::timeout(error)
+        } else {
+            Self::response(error)
+        }
+    }
+}
+
+#[cfg(feature = "hyper-util")]
+impl From<hyper_util::client::legacy::Error> for Error {
+    fn from(error: hyper_util::client::legacy::Error) -> Self {
+        if error.is_connect() {
+            Self::connect(error)
+        } else {
+            Self::response(error)
+        }
+    }
+}
+
+impl From<BoxError> for Error {
+    fn from(error: BoxError) -> Self {
+        Self::response(error)
+    }
+}
+
+/// A specialized [`Result`](std::result::Result) using this crate's
+/// [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::Error;
+
+    #[test]
+    fn test_is_kind_predicates_are_mutually_exclusive() {
+        let errors = [
+            Error::builder(io::Error::other("bad uri")),
+            Error::connect(io::Error::other("refused")),
+            Error::timeout(io::Error::other("deadline exceeded")),
+            Error::response(io::Error::other("decode failed")),
+        ];
+
+        assert!(errors[0].is_builder());
+        assert!(errors[1].is_connect());
+        assert!(errors[2].is_timeout());
+        assert!(errors[3].is_response());
+
+        for (i, error) in errors.iter().enumerate() {
+            let flags = [
+                error.is_builder(),
+                error.is_connect(),
+                error.is_timeout(),
+                error.is_response(),
+            ];
+            assert_eq!(flags.iter().filter(|flag| **flag).count(), 1);
+            assert!(flags[i]);
+        }
+    }
+}
@@ -0,0 +1,181 @@
+//! A [`Layer`]/[`Service`] pair that correlates data extracted from an
+//! outgoing request with the response eventually produced for it.
+
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use pin_project::pin_project;
+use tower::{Layer, Service};
+
+/// [`Layer`] that produces a [`MapFutureWithRequestData`].
+///
+/// `req_fn` extracts some data `T` from each outgoing request (an auth
+/// subject, a route label, a captured header, a start [`Instant`]); `map_fn`
+/// later combines that data with the inner service's result to produce the
+/// final response.
+///
+/// [`Instant`]: std::time::Instant
+#[derive(Debug, Clone)]
+pub struct MapFutureWithRequestDataLayer<ReqFn, MapFn> {
+    req_fn: ReqFn,
+    map_fn: MapFn,
+}
+
+impl<ReqFn, MapFn> MapFutureWithRequestDataLayer<ReqFn, MapFn> {
+    /// Creates a new [`MapFutureWithRequestDataLayer`] from a request-data
+    /// extractor and a response mapper.
+    pub fn new(req_fn: ReqFn, map_fn: MapFn) -> Self {
+        Self { req_fn, map_fn }
+    }
+}
+
+impl<S, ReqFn, MapFn> Layer<S> for MapFutureWithRequestDataLayer<ReqFn, MapFn>
+where
+    ReqFn: Clone,
+    MapFn: Clone,
+{
+    type Service = MapFutureWithRequestData<S, ReqFn, MapFn>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        MapFutureWithRequestData {
+            inner,
+            req_fn: self.req_fn.clone(),
+            map_fn: self.map_fn.clone(),
+        }
+    }
+}
+
+/// [`Service`] that extracts data from each outgoing request via `req_fn`
+/// and combines it with the resolved response (or error) via `map_fn`.
+///
+/// See [`MapFutureWithRequestDataLayer`] for details.
+#[derive(Debug, Clone)]
+pub struct MapFutureWithRequestData<S, ReqFn, MapFn> {
+    inner: S,
+    req_fn: ReqFn,
+    map_fn: MapFn,
+}
+
+impl<S, ReqFn, MapFn, ReqBody, T, Response> Service<http::Request<ReqBody>>
+    for MapFutureWithRequestData<S, ReqFn, MapFn>
+where
+    S: Service<http::Request<ReqBody>>,
+    ReqFn: FnMut(&http::Request<ReqBody>) -> T,
+    MapFn: FnMut(T, Result<S::Response, S::Error>) -> Result<Response, S::Error> + Clone,
+{
+    type Response = Response;
+    type Error = S::Error;
+    type Future = MapFutureWithRequestDataFuture<S::Future, T, MapFn>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: http::Request<ReqBody>) -> Self::Future {
+        let data = (self.req_fn)(&req);
+        MapFutureWithRequestDataFuture {
+            future: self.inner.call(req),
+            data: Some(data),
+            map_fn: self.map_fn.clone(),
+        }
+    }
+}
+
+/// Response [`Future`] for [`MapFutureWithRequestData`].
+#[pin_project]
+#[derive(Debug)]
+pub struct MapFutureWithRequestDataFuture<F, T, MapFn> {
+    #[pin]
+    future: F,
+    data: Option<T>,
+    map_fn: MapFn,
+}
+
+impl<F, T, MapFn, In, Response, Error> Future for MapFutureWithRequestDataFuture<F, T, MapFn>
+where
+    F: Future<Output = Result<In, Error>>,
+    MapFn: FnMut(T, Result<In, Error>) -> Result<Response, Error>,
+{
+    type Output = Result<Response, Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let result = ready!(this.future.poll(cx));
+        let data = this.data.take().expect("polled after completion");
+        Poll::Ready((this.map_fn)(data, result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use http::{HeaderName, HeaderValue, Request, Response};
+    use pretty_assertions::assert_eq;
+    use tower::{service_fn, Service, ServiceBuilder, ServiceExt};
+
+    use super::MapFutureWithRequestDataLayer;
+
+    #[tokio::test]
+    async fn test_map_future_with_request_data() {
+        let x_request_id = HeaderName::from_static("x-request-id");
+
+        let mut service = ServiceBuilder::new()
+            .layer(MapFutureWithRequestDataLayer::new(
+                move |req: &Request<()>| req.headers().get(&x_request_id).cloned(),
+                |request_id, result: Result<Response<()>, Infallible>| {
+                    result.map(|mut response| {
+                        if let Some(request_id) = request_id {
+                            response.headers_mut().insert(
+                                HeaderName::from_static("x-request-id"),
+                                request_id,
+                            );
+                        }
+                        response
+                    })
+                },
+            ))
+            .service(service_fn(|_req: Request<()>| async {
+                Ok::<_, Infallible>(Response::new(()))
+            }));
+
+        let request = Request::builder()
+            .header("x-request-id", "42")
+            .body(())
+            .unwrap();
+        let response = service.ready().await.unwrap().call(request).await.unwrap();
+
+        assert_eq!(
+            response.headers().get("x-request-id").unwrap(),
+            HeaderValue::from_static("42")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_map_future_with_request_data_changes_response_type() {
+        // `map_fn` is allowed to turn the inner service's response into an
+        // unrelated type; this should compile and run even though the inner
+        // future's `Output` is not `Result<Response, Error>`.
+        let mut service = ServiceBuilder::new()
+            .layer(MapFutureWithRequestDataLayer::new(
+                |req: &Request<()>| req.headers().len(),
+                |header_count: usize, result: Result<Response<()>, Infallible>| {
+                    result.map(|_response| header_count)
+                },
+            ))
+            .service(service_fn(|_req: Request<()>| async {
+                Ok::<_, Infallible>(Response::new(()))
+            }));
+
+        let request = Request::builder()
+            .header("x-request-id", "42")
+            .body(())
+            .unwrap();
+        let header_count = service.ready().await.unwrap().call(request).await.unwrap();
+
+        assert_eq!(header_count, 1);
+    }
+}
@@ -0,0 +1,8 @@
+//! Generic `tower` [`Layer`](tower::Layer)s that are not tied to a specific
+//! HTTP client backend.
+
+mod map_future_with_request_data;
+
+pub use self::map_future_with_request_data::{
+    MapFutureWithRequestData, MapFutureWithRequestDataFuture, MapFutureWithRequestDataLayer,
+};
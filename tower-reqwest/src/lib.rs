@@ -0,0 +1,51 @@
+//! Tower [`Service`] adapters that bridge concrete HTTP client backends
+//! (e.g. [`reqwest`]) to the generic `http::Request` / `http::Response`
+//! types expected by `tower`/`tower-http` middleware.
+//!
+//! [`reqwest`]: https://crates.io/crates/reqwest
+//! [`Service`]: tower::Service
+
+mod body;
+mod error;
+#[cfg(feature = "util")]
+mod util;
+
+pub mod adapters;
+pub mod layers;
+
+pub use crate::{
+    body::ResponseBody,
+    error::{Error, Result},
+};
+#[cfg(feature = "util")]
+pub use crate::util::{RequestBuilderExt, ResponseExt};
+
+use tower::Layer;
+
+/// Tower [`Service`] that adapts a backend HTTP client service to the
+/// generic `http::Request<ReqBody>` / `http::Response<ResponseBody>` types
+/// understood by `tower-http` middleware.
+///
+/// Build one with [`HttpClientLayer`] rather than constructing it directly.
+#[derive(Debug, Clone)]
+pub struct HttpClientService<S>(S);
+
+impl<S> HttpClientService<S> {
+    /// Wraps `inner` in an [`HttpClientService`].
+    pub fn new(inner: S) -> Self {
+        Self(inner)
+    }
+}
+
+/// [`Layer`] that produces an [`HttpClientService`] wrapping the given
+/// backend client service.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HttpClientLayer;
+
+impl<S> Layer<S> for HttpClientLayer {
+    type Service = HttpClientService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        HttpClientService::new(inner)
+    }
+}
@@ -0,0 +1,56 @@
+//! Ergonomic request construction and response reading helpers, mirroring
+//! [`reqwest::RequestBuilder`]'s convenience methods.
+//!
+//! [`reqwest::RequestBuilder`]: https://docs.rs/reqwest/latest/reqwest/struct.RequestBuilder.html
+
+use std::future::Future;
+
+use hyper::body::Bytes;
+use http_body_util::{BodyExt, Empty};
+
+use crate::{Error, ResponseBody};
+
+/// Extension trait for [`http::request::Builder`] adding ergonomic
+/// construction of bodyless requests.
+pub trait RequestBuilderExt {
+    /// Finishes building the request with an empty body.
+    fn empty_body(self) -> http::Result<http::Request<Empty<Bytes>>>;
+}
+
+impl RequestBuilderExt for http::request::Builder {
+    fn empty_body(self) -> http::Result<http::Request<Empty<Bytes>>> {
+        self.body(Empty::new())
+    }
+}
+
+/// Extension trait for [`http::Response<ResponseBody>`] adding ergonomic,
+/// `reqwest`-like methods for reading the response body.
+pub trait ResponseExt {
+    /// Collects the response body and returns its raw bytes.
+    fn bytes(self) -> impl Future<Output = crate::Result<Bytes>> + Send;
+
+    /// Collects the response body and decodes it as UTF-8 text.
+    fn text(self) -> impl Future<Output = crate::Result<String>> + Send;
+
+    /// Collects the response body and deserializes it as JSON.
+    #[cfg(feature = "json")]
+    fn json<T: serde::de::DeserializeOwned>(self) -> impl Future<Output = crate::Result<T>> + Send;
+}
+
+impl ResponseExt for http::Response<ResponseBody> {
+    async fn bytes(self) -> crate::Result<Bytes> {
+        let bytes = self.into_body().collect().await?.to_bytes();
+        Ok(bytes)
+    }
+
+    async fn text(self) -> crate::Result<String> {
+        let bytes = self.bytes().await?;
+        String::from_utf8(bytes.into()).map_err(Error::response)
+    }
+
+    #[cfg(feature = "json")]
+    async fn json<T: serde::de::DeserializeOwned>(self) -> crate::Result<T> {
+        let bytes = self.bytes().await?;
+        serde_json::from_slice(&bytes).map_err(Error::response)
+    }
+}